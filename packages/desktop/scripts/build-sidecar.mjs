@@ -1,4 +1,5 @@
 import fs from 'node:fs/promises';
+import os from 'node:os';
 import path from 'node:path';
 import { spawnSync } from 'node:child_process';
 import { fileURLToPath } from 'node:url';
@@ -12,6 +13,7 @@ const desktopTauriDir = path.join(repoRoot, 'packages', 'desktop', 'src-tauri');
 
 const resourcesDir = path.join(desktopTauriDir, 'resources');
 const resourcesWebDistDir = path.join(resourcesDir, 'web-dist');
+const resourcesWebBundlePath = path.join(resourcesDir, 'openchamber-web.tgz');
 const webDistDir = path.join(webDir, 'dist');
 
 const sidecarsDir = path.join(desktopTauriDir, 'sidecars');
@@ -110,6 +112,19 @@ await fs.mkdir(resourcesDir, { recursive: true });
 await fs.rm(resourcesWebDistDir, { recursive: true, force: true });
 await copyDir(webDistDir, resourcesWebDistDir);
 
+console.log('[desktop] packing web bundle for SSH remotes...');
+const packDir = await fs.mkdtemp(path.join(os.tmpdir(), 'openchamber-pack-'));
+try {
+  run(process.platform === 'win32' ? 'npm.cmd' : 'npm', ['pack', '--pack-destination', packDir], webDir);
+  const tarball = (await fs.readdir(packDir)).find((name) => name.endsWith('.tgz'));
+  if (!tarball) {
+    throw new Error('npm pack did not produce a tarball');
+  }
+  await fs.copyFile(path.join(packDir, tarball), resourcesWebBundlePath);
+} finally {
+  await fs.rm(packDir, { recursive: true, force: true });
+}
+
 console.log('[desktop] building openchamber-server sidecar...');
 await fs.mkdir(sidecarsDir, { recursive: true });
 
@@ -133,3 +148,4 @@ if (process.platform !== 'win32') {
 
 console.log(`[desktop] sidecar ready: ${sidecarOutPath}`);
 console.log(`[desktop] web assets ready: ${resourcesWebDistDir}`);
+console.log(`[desktop] web bundle ready: ${resourcesWebBundlePath}`);
@@ -3292,6 +3292,62 @@ fn main() {
             remote_ssh::desktop_ssh_status,
             remote_ssh::desktop_ssh_logs,
             remote_ssh::desktop_ssh_logs_clear,
+            remote_ssh::desktop_ssh_validate_bind_host,
+            remote_ssh::desktop_ssh_http_client_capabilities,
+            remote_ssh::desktop_ssh_next_retry_eta,
+            remote_ssh::desktop_ssh_import_hosts_from_text,
+            remote_ssh::desktop_ssh_remote_identity,
+            remote_ssh::desktop_ssh_verify_askpass,
+            remote_ssh::desktop_ssh_local_url,
+            remote_ssh::desktop_ssh_forward_drift,
+            remote_ssh::desktop_ssh_control_socket_dir_get,
+            remote_ssh::desktop_ssh_control_socket_dir_set,
+            remote_ssh::desktop_ssh_recover,
+            remote_ssh::desktop_ssh_test_connection,
+            remote_ssh::desktop_ssh_connection_details,
+            remote_ssh::desktop_ssh_logs_subscribe,
+            remote_ssh::desktop_ssh_logs_unsubscribe,
+            remote_ssh::desktop_ssh_logs_path,
+            remote_ssh::desktop_ssh_preview_probe_script,
+            remote_ssh::desktop_ssh_plaintext_secret_count,
+            remote_ssh::desktop_ssh_migrate_secrets_to_keychain,
+            remote_ssh::desktop_ssh_reconnect_state,
+            remote_ssh::desktop_ssh_failed_forwards,
+            remote_ssh::desktop_ssh_phase_history,
+            remote_ssh::desktop_ssh_explain,
+            remote_ssh::desktop_ssh_reconnect_config,
+            remote_ssh::desktop_ssh_forward_statuses,
+            remote_ssh::desktop_ssh_forward_set_enabled,
+            remote_ssh::desktop_ssh_test_forward_target,
+            remote_ssh::desktop_ssh_clear_secret,
+            remote_ssh::desktop_ssh_remote_free_ports,
+            remote_ssh::desktop_ssh_cancel_shutdown,
+            remote_ssh::desktop_ssh_metrics,
+            remote_ssh::desktop_ssh_recover_settings,
+            remote_ssh::desktop_ssh_connect_many,
+            remote_ssh::desktop_ssh_disconnect_many,
+            remote_ssh::desktop_ssh_forward_error,
+            remote_ssh::desktop_ssh_check_key_passphrase,
+            remote_ssh::desktop_ssh_last_connect_timeline,
+            remote_ssh::desktop_ssh_rebind_local_port,
+            remote_ssh::desktop_ssh_local_ports_in_use,
+            remote_ssh::desktop_ssh_hard_reset,
+            remote_ssh::desktop_ssh_install_failure,
+            remote_ssh::desktop_ssh_check_download_install,
+            remote_ssh::desktop_ssh_remote_info,
+            remote_ssh::desktop_ssh_remote_path,
+            remote_ssh::desktop_ssh_cancel,
+            remote_ssh::desktop_ssh_check_remote_forwarding,
+            remote_ssh::desktop_ssh_adopt_existing,
+            remote_ssh::desktop_ssh_preview_master_args,
+            remote_ssh::desktop_ssh_confirm_host_key,
+            remote_ssh::desktop_ssh_check_control_collisions,
+            remote_ssh::desktop_ssh_phase_stats,
+            remote_ssh::desktop_ssh_verify_remote_identity,
+            remote_ssh::desktop_ssh_list_orphans,
+            remote_ssh::desktop_ssh_kill_orphans,
+            remote_ssh::desktop_ssh_instance_upsert,
+            remote_ssh::desktop_ssh_instance_delete,
             desktop_read_file,
         ])
         .setup(|app| {
@@ -3301,6 +3357,10 @@ fn main() {
                 log::error!("[desktop] failed to create startup window: {err}");
             }
 
+            if let Some(state) = handle.try_state::<DesktopSshManagerState>() {
+                state.auto_connect_on_startup(&handle);
+            }
+
             tauri::async_runtime::spawn(async move {
                 let local_url = if cfg!(debug_assertions) {
                     let dev_url = "http://127.0.0.1:3901".to_string();
@@ -123,10 +123,16 @@ const sshPhaseLabel = (phase: DesktopSshInstanceStatus['phase'] | undefined): st
       return 'Ready';
     case 'error':
       return 'Error';
+    case 'unsupported':
+      return 'Unsupported platform';
+    case 'cancelled':
+      return 'Cancelled';
     case 'degraded':
       return 'Reconnecting';
     case 'config_resolved':
       return 'Resolving config';
+    case 'queued':
+      return 'Waiting to connect';
     case 'auth_check':
       return 'Checking auth';
     case 'master_connecting':
@@ -151,9 +157,9 @@ const sshPhaseLabel = (phase: DesktopSshInstanceStatus['phase'] | undefined): st
 const sshPhaseToHostStatus = (
   phase: DesktopSshInstanceStatus['phase'] | undefined,
 ): HostProbeResult['status'] | null => {
-  if (!phase || phase === 'idle') return null;
+  if (!phase || phase === 'idle' || phase === 'cancelled') return null;
   if (phase === 'ready') return 'ok';
-  if (phase === 'error') return 'unreachable';
+  if (phase === 'error' || phase === 'unsupported') return 'unreachable';
   return 'auth';
 };
 
@@ -185,7 +191,7 @@ const waitForSshReady = async (
       if (status.phase === 'ready') {
         return status;
       }
-      if (status.phase === 'error') {
+      if (status.phase === 'error' || status.phase === 'unsupported') {
         throw new Error(status.detail || 'SSH connection failed');
       }
     }
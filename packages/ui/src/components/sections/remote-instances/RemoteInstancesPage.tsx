@@ -3,6 +3,7 @@ import { Button } from '@/components/ui/button';
 import { Input } from '@/components/ui/input';
 import { NumberInput } from '@/components/ui/number-input';
 import { Switch } from '@/components/ui/switch';
+import { Textarea } from '@/components/ui/textarea';
 import {
   Select,
   SelectContent,
@@ -44,6 +45,10 @@ import { openExternalUrl } from '@/lib/url';
 import {
   desktopSshLogsClear,
   desktopSshLogs,
+  desktopSshMigrateSecretsToKeychain,
+  desktopSshPlaintextSecretCount,
+  type DesktopSshHostKeyPolicy,
+  type DesktopSshRemoteHttpTool,
   type DesktopSshInstance,
   type DesktopSshPortForward,
   type DesktopSshPortForwardType,
@@ -62,6 +67,8 @@ const phaseLabel = (phase?: string): string => {
   switch (phase) {
     case 'config_resolved':
       return 'Resolving configuration';
+    case 'queued':
+      return 'Queued';
     case 'auth_check':
       return 'Checking auth';
     case 'master_connecting':
@@ -84,6 +91,10 @@ const phaseLabel = (phase?: string): string => {
       return 'Reconnecting';
     case 'error':
       return 'Error';
+    case 'unsupported':
+      return 'Unsupported platform';
+    case 'cancelled':
+      return 'Cancelled';
     default:
       return 'Idle';
   }
@@ -91,6 +102,7 @@ const phaseLabel = (phase?: string): string => {
 
 const CONNECTING_PHASES = new Set<string>([
   'config_resolved',
+  'queued',
   'auth_check',
   'master_connecting',
   'remote_probe',
@@ -109,6 +121,9 @@ const phaseDotClass = (phase?: string): string => {
   if (phase === 'ready') {
     return 'bg-[var(--status-success)] animate-pulse';
   }
+  if (phase === 'unsupported') {
+    return 'bg-[var(--status-error)]';
+  }
   if (phase === 'error') {
     return 'bg-[var(--status-error)] animate-pulse';
   }
@@ -161,6 +176,44 @@ const HintLabel: React.FC<{ label: string; hint: React.ReactNode }> = ({ label,
   );
 };
 
+const formatRemoteEnv = (env: Array<[string, string]> | undefined): string => {
+  return (env ?? []).map(([name, value]) => `${name}=${value}`).join('\n');
+};
+
+const parseRemoteEnv = (text: string): Array<[string, string]> => {
+  return text
+    .split('\n')
+    .map((line) => line.trim())
+    .filter(Boolean)
+    .map((line): [string, string] => {
+      const index = line.indexOf('=');
+      return index === -1 ? [line, ''] : [line.slice(0, index).trim(), line.slice(index + 1)];
+    });
+};
+
+// Edited as free text and parsed on blur so partially typed lines are not rewritten.
+const RemoteEnvField: React.FC<{
+  value: Array<[string, string]> | undefined;
+  onCommit: (env: Array<[string, string]>) => void;
+}> = ({ value, onCommit }) => {
+  const [text, setText] = React.useState(() => formatRemoteEnv(value));
+
+  React.useEffect(() => {
+    setText(formatRemoteEnv(value));
+  }, [value]);
+
+  return (
+    <Textarea
+      className="min-h-[56px] md:max-w-sm font-mono typography-meta"
+      value={text}
+      onChange={(event) => setText(event.target.value)}
+      onBlur={() => onCommit(parseRemoteEnv(text))}
+      placeholder={'NODE_OPTIONS=--max-old-space-size=4096\nHTTPS_PROXY=http://proxy:3128'}
+      rows={2}
+    />
+  );
+};
+
 const forwardTypeDescription = (type: DesktopSshPortForwardType): string => {
   switch (type) {
     case 'remote':
@@ -230,13 +283,11 @@ const normalizeForSave = (instance: DesktopSshInstance): DesktopSshInstance => {
     sshCommand: trimmedCommand,
     ...(nickname ? { nickname } : { nickname: undefined }),
     connectionTimeoutSec: Math.max(5, Math.min(240, Math.round(instance.connectionTimeoutSec || 60))),
+    remoteShell: instance.remoteShell?.trim() || undefined,
+    askpassBinary: instance.askpassBinary?.trim() || undefined,
     localForward: {
       ...instance.localForward,
-      bindHost:
-        instance.localForward.bindHost === 'localhost' ||
-        instance.localForward.bindHost === '0.0.0.0'
-          ? instance.localForward.bindHost
-          : '127.0.0.1',
+      bindHost: instance.localForward.bindHost.trim() || '127.0.0.1',
       preferredLocalPort:
         typeof instance.localForward.preferredLocalPort === 'number'
           ? Math.max(1, Math.min(65535, Math.round(instance.localForward.preferredLocalPort)))
@@ -248,6 +299,9 @@ const normalizeForSave = (instance: DesktopSshInstance): DesktopSshInstance => {
         typeof instance.remoteOpenchamber.preferredPort === 'number'
           ? Math.max(1, Math.min(65535, Math.round(instance.remoteOpenchamber.preferredPort)))
           : undefined,
+      remoteSocketPath: instance.remoteOpenchamber.remoteSocketPath?.trim() || undefined,
+      pinnedVersion: instance.remoteOpenchamber.pinnedVersion?.trim() || undefined,
+      remoteVerifyCommand: instance.remoteOpenchamber.remoteVerifyCommand?.trim() || undefined,
     },
     portForwards: forwards,
   };
@@ -268,6 +322,8 @@ export const RemoteInstancesPage: React.FC = () => {
   const removeInstance = useDesktopSshStore((state) => state.removeInstance);
   const connect = useDesktopSshStore((state) => state.connect);
   const disconnect = useDesktopSshStore((state) => state.disconnect);
+  const cancel = useDesktopSshStore((state) => state.cancel);
+  const confirmHostKey = useDesktopSshStore((state) => state.confirmHostKey);
   const retry = useDesktopSshStore((state) => state.retry);
 
   const selectedId = useUIStore((state) => state.settingsRemoteInstancesSelectedId);
@@ -296,6 +352,30 @@ export const RemoteInstancesPage: React.FC = () => {
     void loadImports();
   }, [load, loadImports]);
 
+  React.useEffect(() => {
+    let cancelled = false;
+    void (async () => {
+      const count = await desktopSshPlaintextSecretCount().catch(() => 0);
+      if (cancelled || count === 0) return;
+      const migrate = window.confirm(
+        `${count} SSH secret${count === 1 ? ' is' : 's are'} stored as plaintext in settings.json. Move ${count === 1 ? 'it' : 'them'} to the OS keychain?`,
+      );
+      if (!migrate) return;
+      try {
+        const moved = await desktopSshMigrateSecretsToKeychain();
+        toast.success(`Moved ${moved} secret${moved === 1 ? '' : 's'} to the OS keychain`);
+        await load();
+      } catch (error) {
+        toast.error('Failed to move secrets to the OS keychain', {
+          description: error instanceof Error ? error.message : String(error),
+        });
+      }
+    })();
+    return () => {
+      cancelled = true;
+    };
+  }, [load]);
+
   React.useEffect(() => {
     setDraft(selectedInstance);
   }, [selectedInstance]);
@@ -361,22 +441,34 @@ export const RemoteInstancesPage: React.FC = () => {
     if (
       normalized.auth.sshPassword?.enabled &&
       normalized.auth.sshPassword.value?.trim() &&
-      normalized.auth.sshPassword.store !== 'settings'
+      normalized.auth.sshPassword.store === 'never'
     ) {
-      const store = window.confirm('Store SSH password in settings.json as plaintext?');
-      normalized.auth.sshPassword.store = store ? 'settings' : 'never';
+      const store = window.confirm('Store SSH password in the OS keychain?');
+      normalized.auth.sshPassword.store = store ? 'keychain' : 'never';
       if (!store) {
         normalized.auth.sshPassword.value = undefined;
       }
     }
 
+    if (
+      normalized.auth.sshKeyPassphrase?.enabled &&
+      normalized.auth.sshKeyPassphrase.value?.trim() &&
+      normalized.auth.sshKeyPassphrase.store === 'never'
+    ) {
+      const store = window.confirm('Store SSH key passphrase in the OS keychain?');
+      normalized.auth.sshKeyPassphrase.store = store ? 'keychain' : 'never';
+      if (!store) {
+        normalized.auth.sshKeyPassphrase.value = undefined;
+      }
+    }
+
     if (
       normalized.auth.openchamberPassword?.enabled &&
       normalized.auth.openchamberPassword.value?.trim() &&
-      normalized.auth.openchamberPassword.store !== 'settings'
+      normalized.auth.openchamberPassword.store === 'never'
     ) {
-      const store = window.confirm('Store OpenChamber UI password in settings.json as plaintext?');
-      normalized.auth.openchamberPassword.store = store ? 'settings' : 'never';
+      const store = window.confirm('Store OpenChamber UI password in the OS keychain?');
+      normalized.auth.openchamberPassword.store = store ? 'keychain' : 'never';
       if (!store) {
         normalized.auth.openchamberPassword.value = undefined;
       }
@@ -584,7 +676,11 @@ export const RemoteInstancesPage: React.FC = () => {
     }
 
     setIsPrimaryActionPending(true);
-    const operation = canDisconnect ? disconnect(draft.id) : connectWithPortRecovery();
+    const operation = isConnecting
+      ? cancel(draft.id)
+      : canDisconnect
+        ? disconnect(draft.id)
+        : connectWithPortRecovery();
     void operation
       .catch((error) => {
         const actionLabel = canDisconnect ? (isReady ? 'disconnect' : 'cancel connection') : 'connect';
@@ -595,7 +691,20 @@ export const RemoteInstancesPage: React.FC = () => {
       .finally(() => {
         setIsPrimaryActionPending(false);
       });
-  }, [canDisconnect, connectWithPortRecovery, disconnect, draft, isReady]);
+  }, [cancel, canDisconnect, connectWithPortRecovery, disconnect, draft, isConnecting, isReady]);
+
+  const handleHostKeyAnswer = React.useCallback(async (accept: boolean) => {
+    if (!draft) {
+      return;
+    }
+    try {
+      await confirmHostKey(draft.id, accept);
+    } catch (error) {
+      toast.error('Failed to answer host key prompt', {
+        description: error instanceof Error ? error.message : String(error),
+      });
+    }
+  }, [confirmHostKey, draft]);
 
   const handleRetryAction = React.useCallback(() => {
     if (!draft) {
@@ -741,10 +850,42 @@ export const RemoteInstancesPage: React.FC = () => {
           <span className={`h-2.5 w-2.5 rounded-full ${phaseDotClass(statusPhase)}`} />
           <span>{phaseLabel(statusPhase)}</span>
           {status?.localUrl ? <span className="font-mono text-foreground/80">{status.localUrl}</span> : null}
+          {typeof status?.lastLatencyMs === 'number' ? <span className="tabular-nums">{status.lastLatencyMs} ms</span> : null}
+          {status?.totalReconnects ? (
+            <span className="tabular-nums">
+              {status.totalReconnects} reconnect{status.totalReconnects === 1 ? '' : 's'}
+            </span>
+          ) : null}
           {reconnectAppearsStuck ? <span>reconnect stale</span> : null}
         </div>
       </div>
 
+      {status?.hostKey ? (
+        <div className="mb-6 mx-1 rounded-md border border-border px-3 py-2 space-y-2">
+          <p className="typography-ui-label text-foreground">
+            Confirm the host key for {status.hostKey.host || instanceTitle}
+          </p>
+          <p className="typography-meta text-muted-foreground font-mono break-all">
+            {status.hostKey.keyType ? `${status.hostKey.keyType} ` : ''}
+            {status.hostKey.fingerprint || status.hostKey.prompt}
+          </p>
+          <div className="flex items-center gap-2">
+            <Button type="button" size="xs" className="!font-normal" onClick={() => void handleHostKeyAnswer(true)}>
+              Trust and connect
+            </Button>
+            <Button
+              type="button"
+              variant="outline"
+              size="xs"
+              className="!font-normal"
+              onClick={() => void handleHostKeyAnswer(false)}
+            >
+              Reject
+            </Button>
+          </div>
+        </div>
+      ) : null}
+
       <div className="mb-8">
         <div className="mb-1 px-1 space-y-0.5">
           <h3 className="typography-ui-header font-medium text-foreground">Actions</h3>
@@ -870,6 +1011,166 @@ export const RemoteInstancesPage: React.FC = () => {
               }}
             />
           </div>
+          <div className="flex flex-col gap-1.5 py-1.5 md:flex-row md:items-center md:gap-8">
+            <span className="typography-ui-label text-foreground w-56 shrink-0">Remote shell</span>
+            <Input
+              className="h-7 md:max-w-sm font-mono"
+              value={draft.remoteShell || ''}
+              onChange={(event) =>
+                updateDraft((current) => ({
+                  ...current,
+                  remoteShell: event.target.value || undefined,
+                }))
+              }
+              placeholder="sh -lc"
+            />
+          </div>
+          <div className="flex flex-col gap-1.5 py-1.5 md:flex-row md:items-center md:gap-8">
+            <span className="typography-ui-label text-foreground w-56 shrink-0">Max reconnect attempts</span>
+            <NumberInput
+              containerClassName="w-fit"
+              min={0}
+              max={100}
+              step={1}
+              className="w-16 tabular-nums"
+              value={draft.reconnectMaxAttempts ?? 5}
+              onValueChange={(next) => {
+                updateDraft((current) => ({
+                  ...current,
+                  reconnectMaxAttempts: Number.isFinite(next) ? next : current.reconnectMaxAttempts,
+                }));
+              }}
+            />
+          </div>
+          <div className="flex flex-col gap-1.5 py-1.5 md:flex-row md:items-center md:gap-8">
+            <div className="w-56 shrink-0">
+              <HintLabel
+                label="Health check interval (sec)"
+                hint="Seconds between connection health checks. 0 polls every 2s, then every 10s once the link is stable."
+              />
+            </div>
+            <NumberInput
+              containerClassName="w-fit"
+              min={0}
+              max={300}
+              step={1}
+              className="w-16 tabular-nums"
+              value={draft.monitorIntervalSec ?? 0}
+              onValueChange={(next) => {
+                updateDraft((current) => ({
+                  ...current,
+                  monitorIntervalSec: Number.isFinite(next) && next > 0 ? next : undefined,
+                }));
+              }}
+            />
+          </div>
+          <div className="flex flex-col gap-1.5 py-1.5 md:flex-row md:items-center md:gap-8">
+            <div className="w-56 shrink-0">
+              <HintLabel
+                label="Tunnel ready timeout (sec)"
+                hint="How long to wait for OpenChamber to answer through a new tunnel, after SSH itself connected. Raise it for slow remotes, lower it to fail fast."
+              />
+            </div>
+            <NumberInput
+              containerClassName="w-fit"
+              min={1}
+              max={600}
+              step={1}
+              className="w-16 tabular-nums"
+              value={draft.forwardReadyTimeoutSec ?? 30}
+              onValueChange={(next) => {
+                updateDraft((current) => ({
+                  ...current,
+                  forwardReadyTimeoutSec: Number.isFinite(next) && next > 0 ? next : undefined,
+                }));
+              }}
+            />
+          </div>
+          <div className="flex flex-col gap-1.5 py-1.5 md:flex-row md:items-center md:gap-8">
+            <div className="w-56 shrink-0">
+              <HintLabel
+                label="Tunnel handoff window (ms)"
+                hint="How long to watch a new tunnel helper for handing off to the shared SSH connection. Raise it on slow systems that report unexpected drops."
+              />
+            </div>
+            <NumberInput
+              containerClassName="w-fit"
+              min={50}
+              max={10000}
+              step={50}
+              className="w-20 tabular-nums"
+              value={draft.forwardHandoffWindowMs ?? 1000}
+              onValueChange={(next) => {
+                updateDraft((current) => ({
+                  ...current,
+                  forwardHandoffWindowMs: Number.isFinite(next) && next > 0 ? next : undefined,
+                }));
+              }}
+            />
+          </div>
+          <div className="flex flex-col gap-1.5 py-1.5 md:flex-row md:items-center md:gap-8">
+            <div className="w-56 shrink-0">
+              <HintLabel
+                label="Control check timeout (sec)"
+                hint="How long each SSH connection liveness check may take. Too short for a slow link and healthy connections look dropped."
+              />
+            </div>
+            <NumberInput
+              containerClassName="w-fit"
+              min={1}
+              max={120}
+              step={1}
+              className="w-16 tabular-nums"
+              value={draft.controlCheckTimeoutSec ?? 3}
+              onValueChange={(next) => {
+                updateDraft((current) => ({
+                  ...current,
+                  controlCheckTimeoutSec: Number.isFinite(next) && next > 0 ? next : undefined,
+                }));
+              }}
+            />
+          </div>
+          <div className="flex flex-col gap-1.5 py-1.5 md:flex-row md:items-center md:gap-8">
+            <div className="w-56 shrink-0">
+              <HintLabel
+                label="Failed checks before drop"
+                hint="Consecutive failed health checks before the connection is treated as dropped and reconnected."
+              />
+            </div>
+            <NumberInput
+              containerClassName="w-fit"
+              min={1}
+              max={10}
+              step={1}
+              className="w-16 tabular-nums"
+              value={draft.dropConfirmationsRequired ?? 1}
+              onValueChange={(next) => {
+                updateDraft((current) => ({
+                  ...current,
+                  dropConfirmationsRequired: Number.isFinite(next) ? next : current.dropConfirmationsRequired,
+                }));
+              }}
+            />
+          </div>
+          <div className="flex flex-col gap-1.5 py-1.5 md:flex-row md:items-center md:gap-8">
+            <div className="w-56 shrink-0">
+              <HintLabel
+                label="Connect on startup"
+                hint="Connect this instance automatically when the desktop app launches."
+              />
+            </div>
+            <div className="flex w-full items-center gap-2 md:max-w-xs">
+              <Switch
+                checked={draft.autoConnect}
+                onCheckedChange={(checked) =>
+                  updateDraft((current) => ({
+                    ...current,
+                    autoConnect: checked,
+                  }))
+                }
+              />
+            </div>
+          </div>
         </section>
       </div>
 
@@ -944,6 +1245,54 @@ export const RemoteInstancesPage: React.FC = () => {
             />
           </div>
 
+          {!isManagedMode ? (
+            <div className="flex flex-col gap-1.5 py-1.5 md:flex-row md:items-center md:gap-8">
+              <div className="w-56 shrink-0">
+                <HintLabel
+                  label="Remote socket path"
+                  hint="Unix socket the external server listens on. Use instead of a remote port, not together with one."
+                />
+              </div>
+              <Input
+                className="h-7 md:max-w-sm font-mono"
+                value={draft.remoteOpenchamber.remoteSocketPath || ''}
+                onChange={(event) =>
+                  updateDraft((current) => ({
+                    ...current,
+                    remoteOpenchamber: {
+                      ...current.remoteOpenchamber,
+                      remoteSocketPath: event.target.value || undefined,
+                    },
+                  }))
+                }
+                placeholder="/run/openchamber.sock"
+              />
+            </div>
+          ) : null}
+
+          <div className="flex flex-col gap-1.5 py-1.5 md:flex-row md:items-center md:gap-8">
+            <div className="w-56 shrink-0">
+              <HintLabel
+                label="Base path"
+                hint="Path prefix when OpenChamber is served below the root, e.g. /openchamber behind a reverse proxy."
+              />
+            </div>
+            <Input
+              className="h-7 md:max-w-sm font-mono"
+              value={draft.remoteOpenchamber.remoteBasePath || ''}
+              onChange={(event) =>
+                updateDraft((current) => ({
+                  ...current,
+                  remoteOpenchamber: {
+                    ...current.remoteOpenchamber,
+                    remoteBasePath: event.target.value || undefined,
+                  },
+                }))
+              }
+              placeholder="/"
+            />
+          </div>
+
           {isManagedMode ? (
             <div className="flex flex-col gap-1.5 py-1.5 md:flex-row md:items-center md:gap-8">
               <div className="w-56 shrink-0">
@@ -960,7 +1309,10 @@ export const RemoteInstancesPage: React.FC = () => {
                     remoteOpenchamber: {
                       ...current.remoteOpenchamber,
                       installMethod:
-                        value === 'npm' || value === 'download_release' || value === 'upload_bundle'
+                        value === 'npm' ||
+                        value === 'download_release' ||
+                        value === 'upload_bundle' ||
+                        value === 'manual'
                           ? value
                           : 'bun',
                     },
@@ -975,11 +1327,214 @@ export const RemoteInstancesPage: React.FC = () => {
                   <SelectItem value="npm">npm</SelectItem>
                   <SelectItem value="download_release">download release</SelectItem>
                   <SelectItem value="upload_bundle">upload bundle</SelectItem>
+                  <SelectItem value="manual">manual (show command)</SelectItem>
                 </SelectContent>
               </Select>
             </div>
           ) : null}
 
+          {isManagedMode ? (
+            <div className="flex flex-col gap-1.5 py-1.5 md:flex-row md:items-center md:gap-8">
+              <div className="w-56 shrink-0">
+                <HintLabel
+                  label="Command prefix"
+                  hint="Shell run before every openchamber command, e.g. source ~/.bashrc when openchamber is only on a custom PATH."
+                />
+              </div>
+              <Input
+                className="h-7 md:max-w-sm font-mono"
+                value={draft.remoteOpenchamber.remoteCommandPrefix || ''}
+                onChange={(event) =>
+                  updateDraft((current) => ({
+                    ...current,
+                    remoteOpenchamber: {
+                      ...current.remoteOpenchamber,
+                      remoteCommandPrefix: event.target.value || undefined,
+                    },
+                  }))
+                }
+                placeholder="source ~/.bashrc"
+              />
+            </div>
+          ) : null}
+
+          {isManagedMode ? (
+            <div className="flex flex-col gap-1.5 py-1.5 md:flex-row md:items-start md:gap-8">
+              <div className="w-56 shrink-0">
+                <HintLabel
+                  label="Environment"
+                  hint="Extra variables for the remote openchamber commands, one NAME=value per line."
+                />
+              </div>
+              <RemoteEnvField
+                value={draft.remoteOpenchamber.remoteEnv}
+                onCommit={(env) =>
+                  updateDraft((current) => ({
+                    ...current,
+                    remoteOpenchamber: {
+                      ...current.remoteOpenchamber,
+                      remoteEnv: env.length > 0 ? env : undefined,
+                    },
+                  }))
+                }
+              />
+            </div>
+          ) : null}
+
+          {isManagedMode ? (
+            <div className="flex flex-col gap-1.5 py-1.5 md:flex-row md:items-center md:gap-8">
+              <div className="w-56 shrink-0">
+                <HintLabel
+                  label="Port conflict retries"
+                  hint="Other ports to try when the server cannot start because its port is taken. 0 fails immediately."
+                />
+              </div>
+              <NumberInput
+                containerClassName="w-fit"
+                min={0}
+                max={10}
+                step={1}
+                className="w-16 tabular-nums"
+                value={draft.remoteOpenchamber.startPortRetries}
+                onValueChange={(next) => {
+                  updateDraft((current) => ({
+                    ...current,
+                    remoteOpenchamber: {
+                      ...current.remoteOpenchamber,
+                      startPortRetries: Number.isFinite(next)
+                        ? next
+                        : current.remoteOpenchamber.startPortRetries,
+                    },
+                  }));
+                }}
+              />
+            </div>
+          ) : null}
+
+          <div className="flex flex-col gap-1.5 py-1.5 md:flex-row md:items-center md:gap-8">
+            <div className="w-56 shrink-0">
+              <HintLabel
+                label="Homebrew PATH on macOS"
+                hint="On macOS remotes, add /opt/homebrew/bin and /usr/local/bin to PATH so bun, npm and OpenChamber are found."
+              />
+            </div>
+            <div className="flex w-full items-center gap-2 md:max-w-xs">
+              <Switch
+                checked={draft.remoteOpenchamber.homebrewPathOnDarwin}
+                onCheckedChange={(checked) =>
+                  updateDraft((current) => ({
+                    ...current,
+                    remoteOpenchamber: {
+                      ...current.remoteOpenchamber,
+                      homebrewPathOnDarwin: checked,
+                    },
+                  }))
+                }
+              />
+            </div>
+          </div>
+
+          <div className="flex flex-col gap-1.5 py-1.5 md:flex-row md:items-center md:gap-8">
+            <div className="w-56 shrink-0">
+              <HintLabel
+                label="Remote HTTP client"
+                hint="Tool used on the remote for health checks and shutdown. Auto prefers curl and falls back to wget; a forced tool that is missing fails the connect."
+              />
+            </div>
+            <Select
+              value={draft.remoteOpenchamber.remoteHttpTool}
+              onValueChange={(value) =>
+                updateDraft((current) => ({
+                  ...current,
+                  remoteOpenchamber: {
+                    ...current.remoteOpenchamber,
+                    remoteHttpTool: value === 'curl' || value === 'wget' ? (value as DesktopSshRemoteHttpTool) : 'auto',
+                  },
+                }))
+              }
+            >
+              <SelectTrigger className="h-7 w-fit min-w-[140px]">
+                <SelectValue placeholder="Select HTTP client" />
+              </SelectTrigger>
+              <SelectContent>
+                <SelectItem value="auto">auto</SelectItem>
+                <SelectItem value="curl">curl</SelectItem>
+                <SelectItem value="wget">wget</SelectItem>
+              </SelectContent>
+            </Select>
+          </div>
+
+          {isManagedMode ? (
+            <>
+              <div className="flex flex-col gap-1.5 py-1.5 md:flex-row md:items-center md:gap-8">
+                <div className="w-56 shrink-0">
+                  <HintLabel
+                    label="Auto-update server"
+                    hint="Reinstall the remote server when its version differs. When off, a mismatch is only logged."
+                  />
+                </div>
+                <div className="flex w-full items-center gap-2 md:max-w-xs">
+                  <Switch
+                    checked={draft.remoteOpenchamber.autoUpdate}
+                    onCheckedChange={(checked) =>
+                      updateDraft((current) => ({
+                        ...current,
+                        remoteOpenchamber: {
+                          ...current.remoteOpenchamber,
+                          autoUpdate: checked,
+                        },
+                      }))
+                    }
+                  />
+                </div>
+              </div>
+              <div className="flex flex-col gap-1.5 py-1.5 md:flex-row md:items-center md:gap-8">
+                <div className="w-56 shrink-0">
+                  <HintLabel
+                    label="Pinned version"
+                    hint="Install and keep this server version instead of the desktop app's version."
+                  />
+                </div>
+                <Input
+                  className="h-7 md:max-w-sm font-mono"
+                  value={draft.remoteOpenchamber.pinnedVersion || ''}
+                  onChange={(event) =>
+                    updateDraft((current) => ({
+                      ...current,
+                      remoteOpenchamber: {
+                        ...current.remoteOpenchamber,
+                        pinnedVersion: event.target.value || undefined,
+                      },
+                    }))
+                  }
+                  placeholder="App version"
+                />
+              </div>
+              <div className="flex flex-col gap-1.5 py-1.5 md:flex-row md:items-center md:gap-8">
+                <div className="w-56 shrink-0">
+                  <HintLabel
+                    label="Verify command"
+                    hint="Prints the installed server version. Use when the install exposes a differently named binary or needs a wrapper."
+                  />
+                </div>
+                <Input
+                  className="h-7 md:max-w-sm font-mono"
+                  value={draft.remoteOpenchamber.remoteVerifyCommand || ''}
+                  onChange={(event) =>
+                    updateDraft((current) => ({
+                      ...current,
+                      remoteOpenchamber: {
+                        ...current.remoteOpenchamber,
+                        remoteVerifyCommand: event.target.value || undefined,
+                      },
+                    }))
+                  }
+                  placeholder="openchamber --version"
+                />
+              </div>
+            </>
+          ) : null}
+
           {isManagedMode ? (
             <div className="flex flex-col gap-1.5 py-1.5 md:flex-row md:items-center md:gap-8">
               <div className="w-56 shrink-0">
@@ -1003,7 +1558,27 @@ export const RemoteInstancesPage: React.FC = () => {
                 />
               </div>
             </div>
-          ) : null}
+          ) : (
+            <div className="flex flex-col gap-1.5 py-1.5 md:flex-row md:items-center md:gap-8">
+              <div className="w-56 shrink-0">
+                <HintLabel
+                  label="Log in from the UI"
+                  hint="Treat a login prompt from the remote server as healthy when no UI password is set; you sign in through the forwarded UI."
+                />
+              </div>
+              <div className="flex w-full items-center gap-2 md:max-w-xs">
+                <Switch
+                  checked={draft.uiHandlesAuth}
+                  onCheckedChange={(checked) =>
+                    updateDraft((current) => ({
+                      ...current,
+                      uiHandlesAuth: checked,
+                    }))
+                  }
+                />
+              </div>
+            </div>
+          )}
         </section>
       </div>
 
@@ -1033,7 +1608,7 @@ export const RemoteInstancesPage: React.FC = () => {
                   ...current,
                   localForward: {
                     ...current.localForward,
-                    bindHost: value === 'localhost' || value === '0.0.0.0' ? value : '127.0.0.1',
+                    bindHost: value,
                   },
                 }));
               }}
@@ -1045,6 +1620,9 @@ export const RemoteInstancesPage: React.FC = () => {
                 <SelectItem value="127.0.0.1">127.0.0.1</SelectItem>
                 <SelectItem value="localhost">localhost</SelectItem>
                 <SelectItem value="0.0.0.0">0.0.0.0</SelectItem>
+                {!['127.0.0.1', 'localhost', '0.0.0.0'].includes(draft.localForward.bindHost) ? (
+                  <SelectItem value={draft.localForward.bindHost}>{draft.localForward.bindHost}</SelectItem>
+                ) : null}
               </SelectContent>
             </Select>
           </div>
@@ -1132,12 +1710,142 @@ export const RemoteInstancesPage: React.FC = () => {
                   },
                 }))
               }
-              placeholder="Password or key passphrase"
+              placeholder={
+                draft.auth.sshPassword?.store === 'keychain' && draft.auth.sshPassword.enabled
+                  ? 'Stored in OS keychain'
+                  : 'Password or key passphrase'
+              }
+            />
+          </div>
+
+          <div className="flex flex-col gap-1.5 py-1.5 md:flex-row md:items-center md:gap-8">
+            <span className="typography-ui-label text-foreground w-56 shrink-0">SSH key passphrase (optional)</span>
+            <Input
+              className="h-7 md:max-w-sm"
+              type="password"
+              value={draft.auth.sshKeyPassphrase?.value || ''}
+              onChange={(event) =>
+                updateDraft((current) => ({
+                  ...current,
+                  auth: {
+                    ...current.auth,
+                    sshKeyPassphrase: {
+                      enabled: event.target.value.trim().length > 0,
+                      value: event.target.value,
+                      store: current.auth.sshKeyPassphrase?.store || 'never',
+                    },
+                  },
+                }))
+              }
+              placeholder={
+                draft.auth.sshKeyPassphrase?.store === 'keychain' && draft.auth.sshKeyPassphrase.enabled
+                  ? 'Stored in OS keychain'
+                  : 'Passphrase for the private key'
+              }
+            />
+          </div>
+
+          <div className="flex flex-col gap-1.5 py-1.5 md:flex-row md:items-center md:gap-8">
+            <div className="w-56 shrink-0">
+              <HintLabel
+                label="Host key policy"
+                hint="SSH config keeps StrictHostKeyChecking from ~/.ssh/config; Accept new adds unknown hosts on first use; Ask shows the fingerprint and waits for you. Ignored when the SSH command sets StrictHostKeyChecking."
+              />
+            </div>
+            <Select
+              value={draft.hostKeyPolicy ?? 'ssh_config'}
+              onValueChange={(value) =>
+                updateDraft((current) => ({
+                  ...current,
+                  hostKeyPolicy:
+                    value === 'strict' || value === 'ask' || value === 'accept_new'
+                      ? (value as DesktopSshHostKeyPolicy)
+                      : undefined,
+                }))
+              }
+            >
+              <SelectTrigger className="h-7 w-fit min-w-[140px]">
+                <SelectValue placeholder="Select host key policy" />
+              </SelectTrigger>
+              <SelectContent>
+                <SelectItem value="ssh_config">ssh config</SelectItem>
+                <SelectItem value="accept_new">accept new</SelectItem>
+                <SelectItem value="ask">ask</SelectItem>
+                <SelectItem value="strict">strict</SelectItem>
+              </SelectContent>
+            </Select>
+          </div>
+
+          <div className="flex flex-col gap-1.5 py-1.5 md:flex-row md:items-center md:gap-8">
+            <div className="w-56 shrink-0">
+              <HintLabel
+                label="Allow unknown -o options"
+                hint="Pass -o keys outside the supported list, such as RemoteCommand or LocalForward. ControlMaster, ControlPath, ControlPersist, BatchMode and ProxyCommand stay blocked."
+              />
+            </div>
+            <div className="flex w-full items-center gap-2 md:max-w-xs">
+              <Switch
+                checked={draft.allowUnknownSshOptions}
+                onCheckedChange={(checked) =>
+                  updateDraft((current) => ({
+                    ...current,
+                    allowUnknownSshOptions: checked,
+                  }))
+                }
+              />
+            </div>
+          </div>
+
+          <div className="flex flex-col gap-1.5 py-1.5 md:flex-row md:items-center md:gap-8">
+            <span className="typography-ui-label text-foreground w-56 shrink-0">Askpass program (optional)</span>
+            <Input
+              className="h-7 md:max-w-sm font-mono"
+              value={draft.askpassBinary || ''}
+              onChange={(event) =>
+                updateDraft((current) => ({
+                  ...current,
+                  askpassBinary: event.target.value || undefined,
+                }))
+              }
+              placeholder="/usr/bin/ksshaskpass"
             />
           </div>
 
           <div className="flex flex-col gap-1.5 py-1.5 md:flex-row md:items-center md:gap-8">
-            <span className="typography-ui-label text-foreground w-56 shrink-0">OpenChamber UI password (optional)</span>
+            <div className="w-56 shrink-0">
+              <HintLabel
+                label="OpenChamber UI auth"
+                hint="Password logs in through /auth/session; bearer token is sent as an Authorization header."
+              />
+            </div>
+            <Select
+              value={draft.auth.openchamberAuthKind ?? 'password'}
+              onValueChange={(value) =>
+                updateDraft((current) => ({
+                  ...current,
+                  auth: {
+                    ...current.auth,
+                    openchamberAuthKind: value === 'bearer_token' ? 'bearer_token' : 'password',
+                  },
+                }))
+              }
+            >
+              <SelectTrigger className="h-7 w-fit min-w-[140px]">
+                <SelectValue placeholder="Select auth method" />
+              </SelectTrigger>
+              <SelectContent>
+                <SelectItem value="password">password</SelectItem>
+                <SelectItem value="bearer_token">bearer token</SelectItem>
+              </SelectContent>
+            </Select>
+          </div>
+
+          <div className="flex flex-col gap-1.5 py-1.5 md:flex-row md:items-center md:gap-8">
+            <span className="typography-ui-label text-foreground w-56 shrink-0">
+              {draft.auth.openchamberAuthKind === 'bearer_token'
+                ? 'OpenChamber UI token (optional)'
+                : 'OpenChamber UI password (optional)'}
+            </span>
             <Input
               className="h-7 md:max-w-sm"
               type="password"
@@ -1155,7 +1863,11 @@ export const RemoteInstancesPage: React.FC = () => {
                   },
                 }))
               }
-              placeholder="Protect remote UI with password"
+              placeholder={
+                draft.auth.openchamberPassword?.store === 'keychain' && draft.auth.openchamberPassword.enabled
+                  ? 'Stored in OS keychain'
+                  : 'Protect remote UI with password'
+              }
             />
           </div>
         </section>
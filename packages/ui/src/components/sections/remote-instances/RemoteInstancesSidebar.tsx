@@ -34,6 +34,10 @@ const phaseLabel = (phase?: string): string => {
       return 'Ready';
     case 'error':
       return 'Error';
+    case 'unsupported':
+      return 'Unsupported';
+    case 'cancelled':
+      return 'Cancelled';
     case 'degraded':
       return 'Reconnect';
     case 'installing':
@@ -46,6 +50,8 @@ const phaseLabel = (phase?: string): string => {
       return 'Starting';
     case 'master_connecting':
       return 'Connecting';
+    case 'queued':
+      return 'Queued';
     default:
       return 'Idle';
   }
@@ -15,8 +15,10 @@ type TauriGlobal = {
 };
 
 export type DesktopSshRemoteMode = 'managed' | 'external';
-export type DesktopSshInstallMethod = 'npm' | 'bun' | 'download_release' | 'upload_bundle';
-export type DesktopSshSecretStore = 'never' | 'settings';
+export type DesktopSshInstallMethod = 'npm' | 'bun' | 'download_release' | 'upload_bundle' | 'manual';
+export type DesktopSshSecretStore = 'never' | 'settings' | 'keychain';
+export type DesktopSshHostKeyPolicy = 'strict' | 'accept_new' | 'ask';
+export type DesktopSshRemoteHttpTool = 'auto' | 'curl' | 'wget';
 
 export type DesktopSshStoredSecret = {
   enabled: boolean;
@@ -24,6 +26,8 @@ export type DesktopSshStoredSecret = {
   store: DesktopSshSecretStore;
 };
 
+export type DesktopSshRemoteAuthKind = 'password' | 'bearer_token';
+
 export type DesktopSshPortForwardType = 'local' | 'remote' | 'dynamic';
 
 export type DesktopSshPortForward = {
@@ -43,22 +47,47 @@ export type DesktopSshInstance = {
   sshParsed?: {
     destination: string;
     args: string[];
+    jumpHosts: string[];
   };
   connectionTimeoutSec: number;
+  remoteShell?: string;
+  reconnectMaxAttempts?: number;
+  askpassBinary?: string;
+  hostKeyPolicy?: DesktopSshHostKeyPolicy;
+  allowUnknownSshOptions: boolean;
+  monitorIntervalSec?: number;
+  forwardReadyTimeoutSec?: number;
+  forwardHandoffWindowMs?: number;
+  controlCheckTimeoutSec?: number;
+  dropConfirmationsRequired?: number;
+  autoConnect: boolean;
+  uiHandlesAuth: boolean;
   remoteOpenchamber: {
     mode: DesktopSshRemoteMode;
     keepRunning: boolean;
     preferredPort?: number;
     installMethod: DesktopSshInstallMethod;
     uploadBundleOverSsh: boolean;
+    remoteBasePath?: string;
+    startPortRetries: number;
+    remoteEnv?: Array<[string, string]>;
+    remoteCommandPrefix?: string;
+    remoteSocketPath?: string;
+    autoUpdate: boolean;
+    pinnedVersion?: string;
+    remoteVerifyCommand?: string;
+    homebrewPathOnDarwin: boolean;
+    remoteHttpTool: DesktopSshRemoteHttpTool;
   };
   localForward: {
     preferredLocalPort?: number;
-    bindHost: '127.0.0.1' | 'localhost' | '0.0.0.0';
+    bindHost: string;
   };
   auth: {
     sshPassword?: DesktopSshStoredSecret;
+    sshKeyPassphrase?: DesktopSshStoredSecret;
     openchamberPassword?: DesktopSshStoredSecret;
+    openchamberAuthKind?: DesktopSshRemoteAuthKind;
   };
   portForwards: DesktopSshPortForward[];
 };
@@ -70,6 +99,7 @@ export type DesktopSshInstancesConfig = {
 export type DesktopSshPhase =
   | 'idle'
   | 'config_resolved'
+  | 'queued'
   | 'auth_check'
   | 'master_connecting'
   | 'remote_probe'
@@ -80,7 +110,9 @@ export type DesktopSshPhase =
   | 'forwarding'
   | 'ready'
   | 'degraded'
-  | 'error';
+  | 'error'
+  | 'unsupported'
+  | 'cancelled';
 
 export type DesktopSshInstanceStatus = {
   id: string;
@@ -91,17 +123,83 @@ export type DesktopSshInstanceStatus = {
   remotePort?: number;
   startedByUs: boolean;
   retryAttempt: number;
+  totalReconnects: number;
   requiresUserAction: boolean;
+  reconnectMaxAttempts: number;
+  lastLatencyMs?: number;
+  hostKey?: DesktopSshHostKeyPrompt;
+  updatedAtMs: number;
+};
+
+export type DesktopSshHostKeyPrompt = {
+  host?: string;
+  keyType?: string;
+  fingerprint?: string;
+  prompt: string;
+};
+
+export type DesktopSshMetrics = {
+  lastLatencyMs?: number;
+  avgLatencyMs?: number;
+  reconnects: number;
+  uptimeSec?: number;
+};
+
+export type DesktopSshKeyPassphraseOutcome = 'unlocked' | 'wrong_passphrase' | 'error';
+
+export type DesktopSshKeyPassphraseCheck = {
+  identityFile: string;
+  outcome: DesktopSshKeyPassphraseOutcome;
+  detail?: string;
+};
+
+export type DesktopSshBatchOutcome = {
+  ok: boolean;
+  error?: string;
+};
+
+export type DesktopSshSettingsBackup = {
+  name: string;
+  createdAtMs: number;
+  sizeBytes: number;
+  valid: boolean;
+};
+
+export type DesktopSshForwardPhase = 'active' | 'unreachable' | 'failed' | 'disabled';
+
+export type DesktopSshForwardState = {
+  phase: DesktopSshForwardPhase;
+  detail?: string;
   updatedAtMs: number;
 };
 
+export type DesktopSshForwardStatusEvent = {
+  id: string;
+  forwardId: string;
+  state: DesktopSshForwardState;
+};
+
+export type DesktopSshForwardTargetCheck = {
+  target: string;
+  reachable: boolean;
+  method: string;
+};
+
 export type DesktopSshImportCandidate = {
   host: string;
   pattern: boolean;
   source: string;
   sshCommand: string;
+  hostname?: string;
+  user?: string;
+  port?: number;
+  identityFiles: string[];
+  proxyJump?: string;
+  sources: string[];
 };
 
+export type DesktopSshImportDedup = 'keep_first' | 'keep_all' | 'prefer_source';
+
 const isRecord = (value: unknown): value is Record<string, unknown> => {
   return typeof value === 'object' && value !== null;
 };
@@ -136,7 +234,8 @@ const parseStoredSecret = (value: unknown): DesktopSshStoredSecret | undefined =
   if (!isRecord(value)) return undefined;
   const enabled = readBoolean(value, 'enabled') ?? false;
   const rawStore = readString(value, 'store')?.toLowerCase();
-  const store: DesktopSshSecretStore = rawStore === 'settings' ? 'settings' : 'never';
+  const store: DesktopSshSecretStore =
+    rawStore === 'settings' || rawStore === 'keychain' ? rawStore : 'never';
   const rawValue = readString(value, 'value');
   return {
     enabled,
@@ -182,6 +281,7 @@ const parseInstance = (value: unknown): DesktopSshInstance | null => {
     ? {
         destination: readString(parsedRaw, 'destination') || '',
         args: asStringArray(parsedRaw.args),
+        jumpHosts: asStringArray(parsedRaw.jumpHosts ?? parsedRaw.jump_hosts),
       }
     : undefined;
 
@@ -206,16 +306,15 @@ const parseInstance = (value: unknown): DesktopSshInstance | null => {
   const installMethod: DesktopSshInstallMethod =
     rawInstallMethod === 'npm' ||
     rawInstallMethod === 'download_release' ||
-    rawInstallMethod === 'upload_bundle'
+    rawInstallMethod === 'upload_bundle' ||
+    rawInstallMethod === 'manual'
       ? rawInstallMethod
       : 'bun';
 
-  const bindHostRaw =
+  const bindHost =
     readString(localRaw, 'bindHost') ||
     readString(localRaw, 'bind_host') ||
     '127.0.0.1';
-  const bindHost: '127.0.0.1' | 'localhost' | '0.0.0.0' =
-    bindHostRaw === 'localhost' || bindHostRaw === '0.0.0.0' ? bindHostRaw : '127.0.0.1';
 
   const forwardsRaw = Array.isArray(value.portForwards)
     ? value.portForwards
@@ -227,11 +326,56 @@ const parseInstance = (value: unknown): DesktopSshInstance | null => {
     .map((item) => parseForward(item))
     .filter((item): item is DesktopSshPortForward => Boolean(item));
 
+  const remoteBasePath = readString(remoteRaw, 'remoteBasePath') ?? readString(remoteRaw, 'remote_base_path');
+  const remoteEnvRaw = remoteRaw.remoteEnv ?? remoteRaw.remote_env;
+  const remoteEnv = Array.isArray(remoteEnvRaw)
+    ? remoteEnvRaw.filter(
+        (entry): entry is [string, string] =>
+          Array.isArray(entry) && typeof entry[0] === 'string' && typeof entry[1] === 'string',
+      )
+    : [];
+  const remoteCommandPrefix = (
+    readString(remoteRaw, 'remoteCommandPrefix') ?? readString(remoteRaw, 'remote_command_prefix')
+  )?.trim();
+  const remoteSocketPath = (
+    readString(remoteRaw, 'remoteSocketPath') ?? readString(remoteRaw, 'remote_socket_path')
+  )?.trim();
+  const pinnedVersion = (
+    readString(remoteRaw, 'pinnedVersion') ?? readString(remoteRaw, 'pinned_version')
+  )?.trim();
+  const remoteVerifyCommand = (
+    readString(remoteRaw, 'remoteVerifyCommand') ?? readString(remoteRaw, 'remote_verify_command')
+  )?.trim();
+  const rawRemoteHttpTool = readString(remoteRaw, 'remoteHttpTool') ?? readString(remoteRaw, 'remote_http_tool');
+  const remoteHttpTool: DesktopSshRemoteHttpTool =
+    rawRemoteHttpTool === 'curl' || rawRemoteHttpTool === 'wget' ? rawRemoteHttpTool : 'auto';
   const preferredPort = readNumber(remoteRaw, 'preferredPort') ?? readNumber(remoteRaw, 'preferred_port');
   const preferredLocalPort =
     readNumber(localRaw, 'preferredLocalPort') ?? readNumber(localRaw, 'preferred_local_port');
+  const reconnectMaxAttempts =
+    readNumber(value, 'reconnectMaxAttempts') ?? readNumber(value, 'reconnect_max_attempts');
+  const askpassBinary = (readString(value, 'askpassBinary') ?? readString(value, 'askpass_binary'))?.trim();
+  const rawHostKeyPolicy = readString(value, 'hostKeyPolicy') ?? readString(value, 'host_key_policy');
+  const hostKeyPolicy: DesktopSshHostKeyPolicy | undefined =
+    rawHostKeyPolicy === 'strict' || rawHostKeyPolicy === 'ask' || rawHostKeyPolicy === 'accept_new'
+      ? rawHostKeyPolicy
+      : undefined;
+  const monitorIntervalSec =
+    readNumber(value, 'monitorIntervalSec') ?? readNumber(value, 'monitor_interval_sec');
+  const forwardReadyTimeoutSec =
+    readNumber(value, 'forwardReadyTimeoutSec') ?? readNumber(value, 'forward_ready_timeout_sec');
+  const forwardHandoffWindowMs =
+    readNumber(value, 'forwardHandoffWindowMs') ?? readNumber(value, 'forward_handoff_window_ms');
+  const controlCheckTimeoutSec =
+    readNumber(value, 'controlCheckTimeoutSec') ?? readNumber(value, 'control_check_timeout_sec');
+  const dropConfirmationsRequired =
+    readNumber(value, 'dropConfirmationsRequired') ?? readNumber(value, 'drop_confirmations_required');
+  const remoteShell = (readString(value, 'remoteShell') ?? readString(value, 'remote_shell'))?.trim();
   const sshPassword = parseStoredSecret(authRaw.sshPassword || authRaw.ssh_password);
+  const sshKeyPassphrase = parseStoredSecret(authRaw.sshKeyPassphrase || authRaw.ssh_key_passphrase);
   const openchamberPassword = parseStoredSecret(authRaw.openchamberPassword || authRaw.openchamber_password);
+  const rawAuthKind = readString(authRaw, 'openchamberAuthKind') ?? readString(authRaw, 'openchamber_auth_kind');
+  const openchamberAuthKind: DesktopSshRemoteAuthKind = rawAuthKind === 'bearer_token' ? 'bearer_token' : 'password';
 
   return {
     id,
@@ -242,6 +386,19 @@ const parseInstance = (value: unknown): DesktopSshInstance | null => {
       readNumber(value, 'connectionTimeoutSec') ??
       readNumber(value, 'connection_timeout_sec') ??
       60,
+    ...(remoteShell ? { remoteShell } : {}),
+    ...(typeof reconnectMaxAttempts === 'number' ? { reconnectMaxAttempts } : {}),
+    ...(askpassBinary ? { askpassBinary } : {}),
+    ...(hostKeyPolicy ? { hostKeyPolicy } : {}),
+    allowUnknownSshOptions:
+      readBoolean(value, 'allowUnknownSshOptions') ?? readBoolean(value, 'allow_unknown_ssh_options') ?? false,
+    ...(typeof monitorIntervalSec === 'number' ? { monitorIntervalSec } : {}),
+    ...(typeof forwardReadyTimeoutSec === 'number' ? { forwardReadyTimeoutSec } : {}),
+    ...(typeof forwardHandoffWindowMs === 'number' ? { forwardHandoffWindowMs } : {}),
+    ...(typeof controlCheckTimeoutSec === 'number' ? { controlCheckTimeoutSec } : {}),
+    ...(typeof dropConfirmationsRequired === 'number' ? { dropConfirmationsRequired } : {}),
+    autoConnect: readBoolean(value, 'autoConnect') ?? readBoolean(value, 'auto_connect') ?? false,
+    uiHandlesAuth: readBoolean(value, 'uiHandlesAuth') ?? readBoolean(value, 'ui_handles_auth') ?? false,
     remoteOpenchamber: {
       mode,
       keepRunning: readBoolean(remoteRaw, 'keepRunning') ?? readBoolean(remoteRaw, 'keep_running') ?? true,
@@ -251,6 +408,20 @@ const parseInstance = (value: unknown): DesktopSshInstance | null => {
         readBoolean(remoteRaw, 'uploadBundleOverSsh') ??
         readBoolean(remoteRaw, 'upload_bundle_over_ssh') ??
         false,
+      ...(remoteBasePath ? { remoteBasePath } : {}),
+      startPortRetries:
+        readNumber(remoteRaw, 'startPortRetries') ?? readNumber(remoteRaw, 'start_port_retries') ?? 0,
+      ...(remoteEnv.length > 0 ? { remoteEnv } : {}),
+      ...(remoteCommandPrefix ? { remoteCommandPrefix } : {}),
+      ...(remoteSocketPath ? { remoteSocketPath } : {}),
+      autoUpdate: readBoolean(remoteRaw, 'autoUpdate') ?? readBoolean(remoteRaw, 'auto_update') ?? true,
+      ...(pinnedVersion ? { pinnedVersion } : {}),
+      ...(remoteVerifyCommand ? { remoteVerifyCommand } : {}),
+      homebrewPathOnDarwin:
+        readBoolean(remoteRaw, 'homebrewPathOnDarwin') ??
+        readBoolean(remoteRaw, 'homebrew_path_on_darwin') ??
+        true,
+      remoteHttpTool,
     },
     localForward: {
       ...(preferredLocalPort ? { preferredLocalPort } : {}),
@@ -258,7 +429,9 @@ const parseInstance = (value: unknown): DesktopSshInstance | null => {
     },
     auth: {
       ...(sshPassword ? { sshPassword } : {}),
+      ...(sshKeyPassphrase ? { sshKeyPassphrase } : {}),
       ...(openchamberPassword ? { openchamberPassword } : {}),
+      openchamberAuthKind,
     },
     portForwards,
   };
@@ -267,6 +440,7 @@ const parseInstance = (value: unknown): DesktopSshInstance | null => {
 const parsePhase = (value: unknown): DesktopSshPhase => {
   switch (value) {
     case 'config_resolved':
+    case 'queued':
     case 'auth_check':
     case 'master_connecting':
     case 'remote_probe':
@@ -278,16 +452,34 @@ const parsePhase = (value: unknown): DesktopSshPhase => {
     case 'ready':
     case 'degraded':
     case 'error':
+    case 'unsupported':
+    case 'cancelled':
       return value;
     default:
       return 'idle';
   }
 };
 
+const parseHostKeyPrompt = (value: unknown): DesktopSshHostKeyPrompt | null => {
+  if (!isRecord(value)) return null;
+  const prompt = readString(value, 'prompt');
+  if (!prompt) return null;
+  const host = readString(value, 'host');
+  const keyType = readString(value, 'keyType') ?? readString(value, 'key_type');
+  const fingerprint = readString(value, 'fingerprint');
+  return {
+    ...(host ? { host } : {}),
+    ...(keyType ? { keyType } : {}),
+    ...(fingerprint ? { fingerprint } : {}),
+    prompt,
+  };
+};
+
 const parseStatus = (value: unknown): DesktopSshInstanceStatus | null => {
   if (!isRecord(value)) return null;
   const id = readString(value, 'id');
   if (!id) return null;
+  const hostKey = parseHostKeyPrompt(value.hostKey ?? value.host_key);
   return {
     id,
     phase: parsePhase(readString(value, 'phase')),
@@ -305,23 +497,60 @@ const parseStatus = (value: unknown): DesktopSshInstanceStatus | null => {
       : {}),
     startedByUs: readBoolean(value, 'startedByUs') ?? readBoolean(value, 'started_by_us') ?? false,
     retryAttempt: readNumber(value, 'retryAttempt') ?? readNumber(value, 'retry_attempt') ?? 0,
+    totalReconnects: readNumber(value, 'totalReconnects') ?? readNumber(value, 'total_reconnects') ?? 0,
+    reconnectMaxAttempts:
+      readNumber(value, 'reconnectMaxAttempts') ?? readNumber(value, 'reconnect_max_attempts') ?? 5,
     requiresUserAction:
       readBoolean(value, 'requiresUserAction') ?? readBoolean(value, 'requires_user_action') ?? false,
+    ...(typeof (readNumber(value, 'lastLatencyMs') ?? readNumber(value, 'last_latency_ms')) === 'number'
+      ? { lastLatencyMs: readNumber(value, 'lastLatencyMs') ?? readNumber(value, 'last_latency_ms') ?? undefined }
+      : {}),
+    ...(hostKey ? { hostKey } : {}),
     updatedAtMs: readNumber(value, 'updatedAtMs') ?? readNumber(value, 'updated_at_ms') ?? Date.now(),
   };
 };
 
+const parseForwardPhase = (value: unknown): DesktopSshForwardPhase => {
+  switch (value) {
+    case 'active':
+    case 'unreachable':
+    case 'failed':
+      return value;
+    default:
+      return 'disabled';
+  }
+};
+
+const parseForwardState = (value: unknown): DesktopSshForwardState | null => {
+  if (!isRecord(value)) return null;
+  return {
+    phase: parseForwardPhase(readString(value, 'phase')),
+    ...(readString(value, 'detail') ? { detail: readString(value, 'detail') || undefined } : {}),
+    updatedAtMs: readNumber(value, 'updatedAtMs') ?? Date.now(),
+  };
+};
+
 const parseImportCandidate = (value: unknown): DesktopSshImportCandidate | null => {
   if (!isRecord(value)) return null;
   const host = readString(value, 'host');
   const source = readString(value, 'source');
   const sshCommand = readString(value, 'sshCommand') || readString(value, 'ssh_command');
   if (!host || !source || !sshCommand) return null;
+  const hostname = readString(value, 'hostname');
+  const user = readString(value, 'user');
+  const port = readNumber(value, 'port');
+  const proxyJump = readString(value, 'proxyJump') ?? readString(value, 'proxy_jump');
   return {
     host,
     source,
     sshCommand,
     pattern: readBoolean(value, 'pattern') ?? false,
+    ...(hostname ? { hostname } : {}),
+    ...(user ? { user } : {}),
+    ...(port !== null ? { port } : {}),
+    identityFiles: asStringArray(value.identityFiles ?? value.identity_files),
+    ...(proxyJump ? { proxyJump } : {}),
+    sources: asStringArray(value.sources),
   };
 };
 
@@ -330,11 +559,18 @@ export const createDesktopSshInstance = (id: string, sshCommand: string): Deskto
     id,
     sshCommand,
     connectionTimeoutSec: 60,
+    allowUnknownSshOptions: false,
+    autoConnect: false,
+    uiHandlesAuth: false,
     remoteOpenchamber: {
       mode: 'managed',
       keepRunning: true,
       installMethod: 'bun',
       uploadBundleOverSsh: false,
+      startPortRetries: 0,
+      autoUpdate: true,
+      homebrewPathOnDarwin: true,
+      remoteHttpTool: 'auto',
     },
     localForward: {
       bindHost: '127.0.0.1',
@@ -378,20 +614,65 @@ export const desktopSshInstancesSet = async (config: DesktopSshInstancesConfig):
   });
 };
 
-export const desktopSshImportHosts = async (): Promise<DesktopSshImportCandidate[]> => {
+export const desktopSshInstanceUpsert = async (instance: DesktopSshInstance): Promise<DesktopSshInstance> => {
+  const invoke = getInvoke();
+  if (!invoke) return instance;
+  const raw = await invoke('desktop_ssh_instance_upsert', { instance });
+  return parseInstance(raw) ?? instance;
+};
+
+export const desktopSshInstanceDelete = async (id: string): Promise<void> => {
+  const invoke = getInvoke();
+  if (!invoke) return;
+  await invoke('desktop_ssh_instance_delete', { id });
+};
+
+export const desktopSshPlaintextSecretCount = async (): Promise<number> => {
+  const invoke = getInvoke();
+  if (!invoke) return 0;
+  const raw = await invoke('desktop_ssh_plaintext_secret_count');
+  return typeof raw === 'number' ? raw : 0;
+};
+
+export const desktopSshMigrateSecretsToKeychain = async (): Promise<number> => {
+  const invoke = getInvoke();
+  if (!invoke) return 0;
+  const raw = await invoke('desktop_ssh_migrate_secrets_to_keychain');
+  return typeof raw === 'number' ? raw : 0;
+};
+
+export const desktopSshClearSecret = async (
+  id: string,
+  which: 'sshPassword' | 'sshKeyPassphrase' | 'openchamberPassword',
+): Promise<void> => {
+  const invoke = getInvoke();
+  if (!invoke) return;
+  await invoke('desktop_ssh_clear_secret', { id, which });
+};
+
+export const desktopSshImportHosts = async (options?: {
+  dedup?: DesktopSshImportDedup;
+  preferredSource?: string;
+}): Promise<DesktopSshImportCandidate[]> => {
   const invoke = getInvoke();
   if (!invoke) return [];
-  const raw = await invoke('desktop_ssh_import_hosts');
+  const raw = await invoke('desktop_ssh_import_hosts', {
+    ...(options?.dedup ? { dedup: options.dedup } : {}),
+    ...(options?.preferredSource ? { preferredSource: options.preferredSource } : {}),
+  });
   if (!Array.isArray(raw)) return [];
   return raw
     .map((item) => parseImportCandidate(item))
     .filter((item): item is DesktopSshImportCandidate => Boolean(item));
 };
 
-export const desktopSshConnect = async (id: string): Promise<void> => {
+export const desktopSshConnect = async (id: string, options?: { force?: boolean }): Promise<void> => {
   const invoke = getInvoke();
   if (!invoke) return;
-  await invoke('desktop_ssh_connect', { id });
+  await invoke('desktop_ssh_connect', {
+    id,
+    ...(options?.force ? { force: true } : {}),
+  });
 };
 
 export const desktopSshDisconnect = async (id: string): Promise<void> => {
@@ -400,6 +681,80 @@ export const desktopSshDisconnect = async (id: string): Promise<void> => {
   await invoke('desktop_ssh_disconnect', { id });
 };
 
+export const desktopSshCancel = async (id: string): Promise<void> => {
+  const invoke = getInvoke();
+  if (!invoke) return;
+  await invoke('desktop_ssh_cancel', { id });
+};
+
+export const desktopSshAdoptExisting = async (): Promise<string[]> => {
+  const invoke = getInvoke();
+  if (!invoke) return [];
+  const raw = await invoke('desktop_ssh_adopt_existing');
+  return asStringArray(raw);
+};
+
+export const desktopSshConfirmHostKey = async (id: string, accept: boolean): Promise<void> => {
+  const invoke = getInvoke();
+  if (!invoke) return;
+  await invoke('desktop_ssh_confirm_host_key', { id, accept });
+};
+
+export const desktopSshHardReset = async (): Promise<void> => {
+  const invoke = getInvoke();
+  if (!invoke) return;
+  await invoke('desktop_ssh_hard_reset');
+};
+
+const parseBatchOutcomes = (raw: unknown): Record<string, DesktopSshBatchOutcome> => {
+  if (!isRecord(raw)) return {};
+  const outcomes: Record<string, DesktopSshBatchOutcome> = {};
+  for (const [id, value] of Object.entries(raw)) {
+    if (!isRecord(value)) continue;
+    const error = readString(value, 'error');
+    outcomes[id] = {
+      ok: readBoolean(value, 'ok') ?? false,
+      ...(error ? { error } : {}),
+    };
+  }
+  return outcomes;
+};
+
+export const desktopSshConnectMany = async (
+  ids: string[],
+): Promise<Record<string, DesktopSshBatchOutcome>> => {
+  const invoke = getInvoke();
+  if (!invoke) return {};
+  return parseBatchOutcomes(await invoke('desktop_ssh_connect_many', { ids }));
+};
+
+export const desktopSshDisconnectMany = async (
+  ids: string[],
+): Promise<Record<string, DesktopSshBatchOutcome>> => {
+  const invoke = getInvoke();
+  if (!invoke) return {};
+  return parseBatchOutcomes(await invoke('desktop_ssh_disconnect_many', { ids }));
+};
+
+export const desktopSshCancelShutdown = async (id: string): Promise<void> => {
+  const invoke = getInvoke();
+  if (!invoke) return;
+  await invoke('desktop_ssh_cancel_shutdown', { id });
+};
+
+export const desktopSshRebindLocalPort = async (
+  id: string,
+  port?: number,
+): Promise<DesktopSshInstanceStatus | null> => {
+  const invoke = getInvoke();
+  if (!invoke) return null;
+  const raw = await invoke('desktop_ssh_rebind_local_port', {
+    id,
+    ...(typeof port === 'number' ? { port } : {}),
+  });
+  return parseStatus(raw);
+};
+
 export const desktopSshStatus = async (id?: string): Promise<DesktopSshInstanceStatus[]> => {
   const invoke = getInvoke();
   if (!invoke) return [];
@@ -412,6 +767,128 @@ export const desktopSshStatus = async (id?: string): Promise<DesktopSshInstanceS
     .filter((item): item is DesktopSshInstanceStatus => Boolean(item));
 };
 
+export const desktopSshMetrics = async (id: string): Promise<DesktopSshMetrics | null> => {
+  const invoke = getInvoke();
+  if (!invoke) return null;
+  const raw = await invoke('desktop_ssh_metrics', { id });
+  if (!isRecord(raw)) return null;
+  const lastLatencyMs = readNumber(raw, 'lastLatencyMs');
+  const avgLatencyMs = readNumber(raw, 'avgLatencyMs');
+  const uptimeSec = readNumber(raw, 'uptimeSec');
+  return {
+    ...(lastLatencyMs !== null ? { lastLatencyMs } : {}),
+    ...(avgLatencyMs !== null ? { avgLatencyMs } : {}),
+    reconnects: readNumber(raw, 'reconnects') ?? 0,
+    ...(uptimeSec !== null ? { uptimeSec } : {}),
+  };
+};
+
+export type DesktopSshPhaseStat = {
+  phase: DesktopSshPhase;
+  samples: number;
+  averageMs: number;
+};
+
+export const desktopSshPhaseStats = async (id: string): Promise<DesktopSshPhaseStat[]> => {
+  const invoke = getInvoke();
+  if (!invoke) return [];
+  const raw = await invoke('desktop_ssh_phase_stats', { id });
+  if (!Array.isArray(raw)) return [];
+  return raw.filter(isRecord).map((entry) => ({
+    phase: parsePhase(entry.phase),
+    samples: readNumber(entry, 'samples') ?? 0,
+    averageMs: readNumber(entry, 'averageMs') ?? 0,
+  }));
+};
+
+export const desktopSshCheckKeyPassphrase = async (id: string): Promise<DesktopSshKeyPassphraseCheck[]> => {
+  const invoke = getInvoke();
+  if (!invoke) return [];
+  const raw = await invoke('desktop_ssh_check_key_passphrase', { id });
+  if (!Array.isArray(raw)) return [];
+  return raw.filter(isRecord).map((item) => {
+    const outcome = readString(item, 'outcome');
+    const detail = readString(item, 'detail');
+    return {
+      identityFile: readString(item, 'identityFile') ?? '',
+      outcome: outcome === 'unlocked' || outcome === 'wrong_passphrase' ? outcome : 'error',
+      ...(detail ? { detail } : {}),
+    };
+  });
+};
+
+export type DesktopSshDownloadInstallCheck = {
+  viable: boolean;
+  downloader?: string;
+  packageManager?: string;
+  platform: string;
+  reasons: string[];
+};
+
+export const desktopSshCheckDownloadInstall = async (id: string): Promise<DesktopSshDownloadInstallCheck | null> => {
+  const invoke = getInvoke();
+  if (!invoke) return null;
+  const raw = await invoke('desktop_ssh_check_download_install', { id });
+  if (!isRecord(raw)) return null;
+  const downloader = readString(raw, 'downloader');
+  const packageManager = readString(raw, 'packageManager');
+  return {
+    viable: readBoolean(raw, 'viable') ?? false,
+    ...(downloader ? { downloader } : {}),
+    ...(packageManager ? { packageManager } : {}),
+    platform: readString(raw, 'platform') ?? '',
+    reasons: Array.isArray(raw.reasons)
+      ? raw.reasons.filter((reason): reason is string => typeof reason === 'string')
+      : [],
+  };
+};
+
+export type DesktopSshRemoteServerIdentity = 'open_chamber' | 'auth_required' | 'unknown';
+
+export type DesktopSshRemoteInfo = {
+  openchamberVersion?: string;
+  runtime?: string;
+  pid?: number;
+  startedAt?: string;
+  identity: DesktopSshRemoteServerIdentity;
+};
+
+export const desktopSshRemoteInfo = async (id: string): Promise<DesktopSshRemoteInfo | null> => {
+  const invoke = getInvoke();
+  if (!invoke) return null;
+  const raw = await invoke('desktop_ssh_remote_info', { id });
+  if (!isRecord(raw)) return null;
+  const openchamberVersion = readString(raw, 'openchamberVersion');
+  const runtime = readString(raw, 'runtime');
+  const pid = readNumber(raw, 'pid');
+  const startedAt = readString(raw, 'startedAt');
+  const identity = readString(raw, 'identity');
+  return {
+    ...(openchamberVersion ? { openchamberVersion } : {}),
+    ...(runtime ? { runtime } : {}),
+    ...(pid !== null ? { pid } : {}),
+    ...(startedAt ? { startedAt } : {}),
+    identity: identity === 'open_chamber' || identity === 'auth_required' ? identity : 'unknown',
+  };
+};
+
+export const desktopSshRecoverSettings = async (
+  restore?: string,
+): Promise<DesktopSshSettingsBackup[]> => {
+  const invoke = getInvoke();
+  if (!invoke) return [];
+  const raw = await invoke('desktop_ssh_recover_settings', {
+    ...(restore ? { restore } : {}),
+  });
+  if (!Array.isArray(raw)) return [];
+  return raw.filter(isRecord).map((item) => ({
+    name: readString(item, 'name') ?? '',
+    createdAtMs: readNumber(item, 'createdAtMs') ?? 0,
+    sizeBytes: readNumber(item, 'sizeBytes') ?? 0,
+    valid: readBoolean(item, 'valid') ?? false,
+  }));
+};
+
 export const desktopSshLogs = async (id: string, limit?: number): Promise<string[]> => {
   const invoke = getInvoke();
   if (!invoke) return [];
@@ -429,6 +906,275 @@ export const desktopSshLogsClear = async (id: string): Promise<void> => {
   await invoke('desktop_ssh_logs_clear', { id });
 };
 
+export const desktopSshForwardStatuses = async (
+  id: string,
+): Promise<Record<string, DesktopSshForwardState>> => {
+  const invoke = getInvoke();
+  if (!invoke) return {};
+  const raw = await invoke('desktop_ssh_forward_statuses', { id });
+  if (!isRecord(raw)) return {};
+  const statuses: Record<string, DesktopSshForwardState> = {};
+  for (const [forwardId, value] of Object.entries(raw)) {
+    const state = parseForwardState(value);
+    if (state) statuses[forwardId] = state;
+  }
+  return statuses;
+};
+
+export const desktopSshForwardError = async (
+  id: string,
+  forwardId: string,
+): Promise<string | null> => {
+  const invoke = getInvoke();
+  if (!invoke) return null;
+  const raw = await invoke('desktop_ssh_forward_error', { id, forwardId });
+  return typeof raw === 'string' && raw.trim() ? raw : null;
+};
+
+export type DesktopSshInstallFailureKind = 'no_package_manager' | 'permission_denied' | 'network' | 'other';
+
+export type DesktopSshInstallFailure = {
+  kind: DesktopSshInstallFailureKind;
+  message: string;
+  hint?: string;
+};
+
+export const desktopSshInstallFailure = async (id: string): Promise<DesktopSshInstallFailure | null> => {
+  const invoke = getInvoke();
+  if (!invoke) return null;
+  const raw = await invoke('desktop_ssh_install_failure', { id });
+  if (!isRecord(raw)) return null;
+  const rawKind = readString(raw, 'kind');
+  const kind: DesktopSshInstallFailureKind =
+    rawKind === 'no_package_manager' || rawKind === 'permission_denied' || rawKind === 'network'
+      ? rawKind
+      : 'other';
+  const hint = readString(raw, 'hint');
+  return {
+    kind,
+    message: readString(raw, 'message') ?? '',
+    ...(hint ? { hint } : {}),
+  };
+};
+
+export const desktopSshForwardSetEnabled = async (
+  id: string,
+  forwardId: string,
+  enabled: boolean,
+): Promise<DesktopSshForwardState | null> => {
+  const invoke = getInvoke();
+  if (!invoke) return null;
+  const raw = await invoke('desktop_ssh_forward_set_enabled', { id, forwardId, enabled });
+  return parseForwardState(raw);
+};
+
+export const desktopSshTestForwardTarget = async (
+  id: string,
+  forward: DesktopSshPortForward,
+): Promise<DesktopSshForwardTargetCheck | null> => {
+  const invoke = getInvoke();
+  if (!invoke) return null;
+  const raw = await invoke('desktop_ssh_test_forward_target', { id, forward });
+  if (!isRecord(raw)) return null;
+  return {
+    target: readString(raw, 'target') ?? '',
+    reachable: readBoolean(raw, 'reachable') ?? false,
+    method: readString(raw, 'method') ?? '',
+  };
+};
+
+export const desktopSshRemoteFreePorts = async (id: string, start: number, end: number): Promise<number[]> => {
+  const invoke = getInvoke();
+  if (!invoke) return [];
+  const raw = await invoke('desktop_ssh_remote_free_ports', { id, start, end });
+  if (!Array.isArray(raw)) return [];
+  return raw.filter((port): port is number => typeof port === 'number' && Number.isFinite(port));
+};
+
+export type DesktopSshRemoteForwardingCheck = {
+  verdict: 'allowed' | 'blocked' | 'unknown';
+  source: string;
+  allowTcpForwarding?: string;
+  gatewayPorts?: string;
+  detail: string;
+};
+
+export const desktopSshCheckRemoteForwarding = async (
+  id: string,
+): Promise<DesktopSshRemoteForwardingCheck | null> => {
+  const invoke = getInvoke();
+  if (!invoke) return null;
+  const raw = await invoke('desktop_ssh_check_remote_forwarding', { id });
+  if (!isRecord(raw)) return null;
+  const verdict = readString(raw, 'verdict');
+  const allowTcpForwarding = readString(raw, 'allowTcpForwarding');
+  const gatewayPorts = readString(raw, 'gatewayPorts');
+  return {
+    verdict: verdict === 'allowed' || verdict === 'blocked' ? verdict : 'unknown',
+    source: readString(raw, 'source') ?? 'none',
+    ...(allowTcpForwarding ? { allowTcpForwarding } : {}),
+    ...(gatewayPorts ? { gatewayPorts } : {}),
+    detail: readString(raw, 'detail') ?? '',
+  };
+};
+
+export const desktopSshRemotePath = async (id: string): Promise<string | null> => {
+  const invoke = getInvoke();
+  if (!invoke) return null;
+  const raw = await invoke('desktop_ssh_remote_path', { id });
+  return typeof raw === 'string' ? raw : null;
+};
+
+export type DesktopSshLocalPortInUse = {
+  port: number;
+  process?: string;
+};
+
+export type DesktopSshMasterArgsPreview = {
+  argv: string[];
+  env: string[];
+};
+
+export const desktopSshPreviewMasterArgs = async (id: string): Promise<DesktopSshMasterArgsPreview | null> => {
+  const invoke = getInvoke();
+  if (!invoke) return null;
+  const raw = await invoke('desktop_ssh_preview_master_args', { id });
+  if (!isRecord(raw)) return null;
+  return {
+    argv: asStringArray(raw.argv),
+    env: asStringArray(raw.env),
+  };
+};
+
+export type DesktopSshControlCollision = {
+  controlPath: string;
+  instanceIds: string[];
+};
+
+export const desktopSshCheckControlCollisions = async (): Promise<DesktopSshControlCollision[]> => {
+  const invoke = getInvoke();
+  if (!invoke) return [];
+  const raw = await invoke('desktop_ssh_check_control_collisions');
+  if (!Array.isArray(raw)) return [];
+  return raw.filter(isRecord).flatMap((entry) => {
+    const controlPath = readString(entry, 'controlPath');
+    if (!controlPath) return [];
+    return [{ controlPath, instanceIds: asStringArray(entry.instanceIds) }];
+  });
+};
+
+export type DesktopSshOrphanSocket = {
+  controlPath: string;
+  instanceId?: string;
+  label?: string;
+};
+
+export type DesktopSshOrphanCleanup = {
+  controlPath: string;
+  instanceId?: string;
+  masterStopped: boolean;
+  removed: boolean;
+};
+
+export const desktopSshListOrphans = async (): Promise<DesktopSshOrphanSocket[]> => {
+  const invoke = getInvoke();
+  if (!invoke) return [];
+  const raw = await invoke('desktop_ssh_list_orphans');
+  if (!Array.isArray(raw)) return [];
+  return raw.filter(isRecord).flatMap((entry) => {
+    const controlPath = readString(entry, 'controlPath');
+    if (!controlPath) return [];
+    const instanceId = readString(entry, 'instanceId');
+    const label = readString(entry, 'label');
+    return [{ controlPath, ...(instanceId ? { instanceId } : {}), ...(label ? { label } : {}) }];
+  });
+};
+
+export const desktopSshKillOrphans = async (): Promise<DesktopSshOrphanCleanup[]> => {
+  const invoke = getInvoke();
+  if (!invoke) return [];
+  const raw = await invoke('desktop_ssh_kill_orphans');
+  if (!Array.isArray(raw)) return [];
+  return raw.filter(isRecord).flatMap((entry) => {
+    const controlPath = readString(entry, 'controlPath');
+    if (!controlPath) return [];
+    const instanceId = readString(entry, 'instanceId');
+    return [
+      {
+        controlPath,
+        ...(instanceId ? { instanceId } : {}),
+        masterStopped: readBoolean(entry, 'masterStopped') ?? false,
+        removed: readBoolean(entry, 'removed') ?? false,
+      },
+    ];
+  });
+};
+
+export type DesktopSshRemoteIdentityCheck = {
+  matches: boolean;
+  expectedVersion: string;
+  identity: DesktopSshRemoteServerIdentity;
+  reportedVersion?: string;
+};
+
+export const desktopSshVerifyRemoteIdentity = async (
+  id: string,
+  expectedVersion: string,
+): Promise<DesktopSshRemoteIdentityCheck | null> => {
+  const invoke = getInvoke();
+  if (!invoke) return null;
+  const raw = await invoke('desktop_ssh_verify_remote_identity', { id, expectedVersion });
+  if (!isRecord(raw)) return null;
+  const reportedVersion = readString(raw, 'reportedVersion');
+  const identity = readString(raw, 'identity');
+  return {
+    matches: readBoolean(raw, 'matches') ?? false,
+    expectedVersion: readString(raw, 'expectedVersion') ?? expectedVersion,
+    identity: identity === 'open_chamber' || identity === 'auth_required' ? identity : 'unknown',
+    ...(reportedVersion ? { reportedVersion } : {}),
+  };
+};
+
+export const desktopSshLocalPortsInUse = async (start: number, end: number): Promise<DesktopSshLocalPortInUse[]> => {
+  const invoke = getInvoke();
+  if (!invoke) return [];
+  const raw = await invoke('desktop_ssh_local_ports_in_use', { start, end });
+  if (!Array.isArray(raw)) return [];
+  return raw.filter(isRecord).flatMap((entry) => {
+    const port = readNumber(entry, 'port');
+    if (port === null) return [];
+    return [{ port, process: readString(entry, 'process') ?? undefined }];
+  });
+};
+
+export const listenDesktopSshForwardStatus = async (
+  listener: (event: DesktopSshForwardStatusEvent) => void,
+): Promise<() => Promise<void>> => {
+  if (!isTauriShell()) {
+    return async () => {};
+  }
+
+  const tauri = (window as unknown as { __TAURI__?: TauriGlobal }).__TAURI__;
+  const listen = tauri?.event?.listen;
+  if (typeof listen !== 'function') {
+    return async () => {};
+  }
+
+  const unlisten = await listen('openchamber:ssh-forward-status', (event) => {
+    const payload = event?.payload;
+    if (!isRecord(payload)) return;
+    const id = readString(payload, 'id');
+    const forwardId = readString(payload, 'forwardId');
+    const state = parseForwardState(payload.state);
+    if (!id || !forwardId || !state) return;
+    listener({ id, forwardId, state });
+  });
+
+  return async () => {
+    await unlisten();
+  };
+};
+
 export const listenDesktopSshStatus = async (
   listener: (status: DesktopSshInstanceStatus) => void,
 ): Promise<() => Promise<void>> => {
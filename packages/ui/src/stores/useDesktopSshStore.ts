@@ -1,9 +1,13 @@
 import { create } from 'zustand';
 import {
   createDesktopSshInstance,
+  desktopSshCancel,
+  desktopSshConfirmHostKey,
   desktopSshConnect,
   desktopSshDisconnect,
   desktopSshImportHosts,
+  desktopSshInstanceDelete,
+  desktopSshInstanceUpsert,
   desktopSshInstancesGet,
   desktopSshInstancesSet,
   desktopSshStatus,
@@ -32,6 +36,8 @@ type DesktopSshState = {
   setInstances: (instances: DesktopSshInstance[]) => Promise<void>;
   connect: (id: string) => Promise<void>;
   disconnect: (id: string) => Promise<void>;
+  cancel: (id: string) => Promise<void>;
+  confirmHostKey: (id: string, accept: boolean) => Promise<void>;
   retry: (id: string) => Promise<void>;
   getStatus: (id: string) => DesktopSshInstanceStatus | null;
   clearError: () => void;
@@ -131,11 +137,23 @@ export const useDesktopSshStore = create<DesktopSshState>((set, get) => ({
   },
 
   upsertInstance: async (instance) => {
-    const current = get().instances;
-    const next = current.some((item) => item.id === instance.id)
-      ? current.map((item) => (item.id === instance.id ? instance : item))
-      : [instance, ...current];
-    await get().setInstances(next);
+    set({ isSaving: true, error: null });
+    try {
+      const saved = await desktopSshInstanceUpsert(instance);
+      set((state) => ({
+        instances: state.instances.some((item) => item.id === saved.id)
+          ? state.instances.map((item) => (item.id === saved.id ? saved : item))
+          : [saved, ...state.instances],
+        isSaving: false,
+      }));
+      await get().refreshStatuses();
+    } catch (error) {
+      set({
+        isSaving: false,
+        error: error instanceof Error ? error.message : String(error),
+      });
+      throw error;
+    }
   },
 
   createFromCommand: async (id, sshCommand, nickname) => {
@@ -148,13 +166,26 @@ export const useDesktopSshStore = create<DesktopSshState>((set, get) => ({
 
   removeInstance: async (id) => {
     await desktopSshDisconnect(id).catch(() => undefined);
-    const next = get().instances.filter((item) => item.id !== id);
-    await get().setInstances(next);
+    set({ isSaving: true, error: null });
+    try {
+      await desktopSshInstanceDelete(id);
+    } catch (error) {
+      set({
+        isSaving: false,
+        error: error instanceof Error ? error.message : String(error),
+      });
+      throw error;
+    }
     set((state) => {
       const statusesById = { ...state.statusesById };
       delete statusesById[id];
-      return { statusesById };
+      return {
+        instances: state.instances.filter((item) => item.id !== id),
+        statusesById,
+        isSaving: false,
+      };
     });
+    await get().refreshStatuses();
   },
 
   connect: async (id) => {
@@ -179,6 +210,28 @@ export const useDesktopSshStore = create<DesktopSshState>((set, get) => ({
     }
   },
 
+  cancel: async (id) => {
+    set({ error: null });
+    try {
+      await desktopSshCancel(id);
+      await get().refreshStatuses();
+    } catch (error) {
+      set({ error: error instanceof Error ? error.message : String(error) });
+      throw error;
+    }
+  },
+
+  confirmHostKey: async (id, accept) => {
+    set({ error: null });
+    try {
+      await desktopSshConfirmHostKey(id, accept);
+      await get().refreshStatuses();
+    } catch (error) {
+      set({ error: error instanceof Error ? error.message : String(error) });
+      throw error;
+    }
+  },
+
   retry: async (id) => {
     await get().connect(id);
   },